@@ -0,0 +1,204 @@
+//! Asynchronous, tokio-based counterpart to [`Server`](crate::Server).
+//!
+//! `AsyncServer` speaks the same XMLRPC protocol as [`Server`](crate::Server), but performs the
+//! HTTP POST with [`reqwest`]'s async client instead of blocking the calling thread.  This makes
+//! it practical to poll many rtorrent instances, or fan out several multicalls, concurrently
+//! from a single tokio task, without any one of them tying up a thread for the duration of the
+//! HTTP round trip.
+//!
+//! The hostname, torrent-loading, download-listing and introspection methods of
+//! [`Server`](crate::Server) have `async fn` counterparts here returning a
+//! [`Result`](crate::Result), and faults decode to [`Error::Fault`] exactly as they do on the
+//! synchronous path (both go through the same
+//! [`From<xmlrpc::Error>`](Error#impl-From<xmlrpc::Error>-for-Error) conversion).
+//! [`AsyncServer::download_list`] returns infohash strings rather than
+//! [`Download`](crate::Download) handles, and there is no async counterpart to
+//! [`Server::ip_filter`](crate::Server::ip_filter).
+//!
+//! ```no_run
+//! use rtorrent_xmlrpc_bindings as rtorrent;
+//!
+//! # async fn example() -> rtorrent::Result<()> {
+//! let my_handle = rtorrent::AsyncServer::new("http://1.2.3.4/RPC2");
+//! println!("Hostname: {}", my_handle.hostname().await?);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+use xmlrpc::{Request, Value};
+
+use crate::{Error, Result};
+
+#[derive(Debug)]
+struct AsyncServerInner {
+    endpoint: String,
+    http: reqwest::Client,
+}
+
+/// `AsyncServer` represents a logical rtorrent instance, reachable over a non-blocking HTTP
+/// transport.
+///
+/// Cloning an `AsyncServer` is cheap; clones share the same underlying [`reqwest::Client`] (and
+/// therefore its connection pool).
+#[derive(Clone, Debug)]
+pub struct AsyncServer {
+    pub(crate) inner: Arc<AsyncServerInner>,
+}
+
+impl AsyncServer {
+    /// Create a new `AsyncServer` bound to the rtorrent XMLRPC endpoint at `endpoint`.
+    ///
+    /// This does not perform any I/O; the connection is established lazily on first use.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            inner: Arc::new(AsyncServerInner {
+                endpoint: endpoint.into(),
+                http: reqwest::Client::new(),
+            }),
+        }
+    }
+
+    pub(crate) fn endpoint(&self) -> &str {
+        &self.inner.endpoint
+    }
+
+    /// Execute a single XMLRPC call against this server's endpoint without blocking the calling
+    /// thread.
+    ///
+    /// This encodes and decodes the request the same way [`Server::invoke`](crate::Server) does,
+    /// so fault handling stays in lockstep with the synchronous path; only the transport (a
+    /// pooled [`reqwest::Client`] instead of `call_url`'s blocking client) differs.
+    pub(crate) async fn invoke(&self, method: &str, params: Vec<Value>) -> Result<Value> {
+        let mut request = Request::new(method);
+        for param in &params {
+            request = request.arg(param.clone());
+        }
+
+        let mut body = Vec::new();
+        request
+            .write_as_xml(&mut body)
+            .expect("writing to an in-memory buffer cannot fail");
+
+        let response = self
+            .inner
+            .http
+            .post(&self.inner.endpoint)
+            .header("Content-Type", "text/xml")
+            .body(body)
+            .send()
+            .await
+            .map_err(Error::Transport)?;
+
+        let body = response.bytes().await.map_err(Error::Transport)?;
+
+        Ok(xmlrpc::parse_response(&body[..])?)
+    }
+
+    /// The hostname rtorrent is running on, per `system.hostname`.
+    pub async fn hostname(&self) -> Result<String> {
+        match self.invoke("system.hostname", vec![]).await? {
+            Value::String(s) => Ok(s),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected a string from system.hostname, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Load a `.torrent` by local file path or URL, optionally starting it immediately, and
+    /// running any extra `commands` (e.g. `"d.directory.set=..."`) against it once loaded.
+    ///
+    /// Wraps `load.normal`/`load.start`.
+    pub async fn load_torrent(&self, path_or_url: &str, start: bool, commands: &[&str]) -> Result<()> {
+        let method = if start { "load.start" } else { "load.normal" };
+        self.load(method, Value::from(path_or_url), commands).await
+    }
+
+    /// Load a `.torrent` from its raw bencoded contents, optionally starting it immediately, and
+    /// running any extra `commands` against it once loaded.
+    ///
+    /// Wraps `load.raw`/`load.raw_start`.
+    pub async fn load_torrent_raw(&self, contents: &[u8], start: bool, commands: &[&str]) -> Result<()> {
+        let method = if start { "load.raw_start" } else { "load.raw" };
+        self.load(method, Value::Binary(contents.to_vec()), commands).await
+    }
+
+    /// Load a magnet URI, optionally starting it immediately, and running any extra `commands`
+    /// against it once loaded.
+    pub async fn load_magnet(&self, uri: &str, start: bool, commands: &[&str]) -> Result<()> {
+        self.load_torrent(uri, start, commands).await
+    }
+
+    async fn load(&self, method: &str, target: Value, commands: &[&str]) -> Result<()> {
+        let mut params = vec![Value::from(""), target];
+        params.extend(commands.iter().map(|c| Value::from(*c)));
+        self.invoke(method, params).await?;
+        Ok(())
+    }
+
+    /// List the infohashes of every download known to rtorrent, per `download_list`.
+    pub async fn download_list(&self) -> Result<Vec<String>> {
+        match self.invoke("download_list", vec![]).await? {
+            Value::Array(hashes) => hashes
+                .into_iter()
+                .map(|h| match h {
+                    Value::String(s) => Ok(s),
+                    other => Err(Error::UnexpectedStructure(format!(
+                        "expected a string infohash, got {:?}",
+                        other
+                    ))),
+                })
+                .collect(),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected an array from download_list, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// List every XMLRPC method this rtorrent instance supports, per `system.listMethods`.
+    pub async fn list_methods(&self) -> Result<Vec<String>> {
+        match self.invoke("system.listMethods", vec![]).await? {
+            Value::Array(methods) => methods
+                .into_iter()
+                .map(|m| match m {
+                    Value::String(s) => Ok(s),
+                    other => Err(Error::UnexpectedStructure(format!(
+                        "expected a string method name, got {:?}",
+                        other
+                    ))),
+                })
+                .collect(),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected an array from system.listMethods, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Get the parameter/return type signature(s) of `name`, per `system.methodSignature`.
+    ///
+    /// Returns an empty `Vec` if rtorrent has no signature registered for `name` -- per the
+    /// XMLRPC introspection convention, this is common and is reported as the bare string
+    /// `"undef"` rather than an empty array.
+    pub async fn method_signature(&self, name: &str) -> Result<Vec<Vec<String>>> {
+        crate::decode_method_signature(self.invoke("system.methodSignature", vec![Value::from(name)]).await?)
+    }
+
+    /// Get the help text rtorrent has registered for `name`, per `system.methodHelp`.
+    pub async fn method_help(&self, name: &str) -> Result<String> {
+        match self.invoke("system.methodHelp", vec![Value::from(name)]).await? {
+            Value::String(s) => Ok(s),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected a string from system.methodHelp, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Check whether this rtorrent instance exposes a method named `name`.
+    pub async fn supports(&self, name: &str) -> Result<bool> {
+        Ok(self.list_methods().await?.iter().any(|m| m == name))
+    }
+}
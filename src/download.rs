@@ -0,0 +1,125 @@
+//! Wraps a single download (torrent) tracked by rtorrent.
+
+use xmlrpc::Value;
+
+use crate::{Error, Result, Server};
+
+/// A single download (torrent) tracked by an rtorrent [`Server`].
+#[derive(Clone, Debug)]
+pub struct Download {
+    server: Server,
+    hash: String,
+}
+
+impl Download {
+    /// Wrap the download identified by `hash` on `server`.
+    ///
+    /// This does not perform any I/O, nor does it check that `hash` is actually a download
+    /// rtorrent knows about.
+    pub fn new(server: &Server, hash: impl Into<String>) -> Self {
+        Self {
+            server: server.clone(),
+            hash: hash.into(),
+        }
+    }
+
+    /// This download's infohash.
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    fn get(&self, method: &str) -> Result<Value> {
+        self.server.invoke(method, vec![Value::from(self.hash.as_str())])
+    }
+}
+
+macro_rules! d_str_getter {
+    ($(#[$meta:meta])* $method:ident, $api:literal) => {
+        $(#[$meta])*
+        pub fn $method(&self) -> Result<String> {
+            match self.get(concat!("d.", $api))? {
+                Value::String(s) => Ok(s),
+                other => Err(Error::UnexpectedStructure(format!(
+                    "expected a string from d.{}, got {:?}",
+                    $api, other
+                ))),
+            }
+        }
+    };
+}
+
+macro_rules! d_i64_getter {
+    ($(#[$meta:meta])* $method:ident, $api:literal) => {
+        $(#[$meta])*
+        pub fn $method(&self) -> Result<i64> {
+            match self.get(concat!("d.", $api))? {
+                Value::Int(i) => Ok(i.into()),
+                Value::Int64(i) => Ok(i),
+                other => Err(Error::UnexpectedStructure(format!(
+                    "expected an integer from d.{}, got {:?}",
+                    $api, other
+                ))),
+            }
+        }
+    };
+}
+
+macro_rules! d_bool_getter {
+    ($(#[$meta:meta])* $method:ident, $api:literal) => {
+        $(#[$meta])*
+        pub fn $method(&self) -> Result<bool> {
+            match self.get(concat!("d.", $api))? {
+                Value::Bool(b) => Ok(b),
+                Value::Int(i) => Ok(i != 0),
+                Value::Int64(i) => Ok(i != 0),
+                other => Err(Error::UnexpectedStructure(format!(
+                    "expected a boolean from d.{}, got {:?}",
+                    $api, other
+                ))),
+            }
+        }
+    };
+}
+
+impl Download {
+    d_str_getter!(
+        /// Get the name of this download.
+        name, "name");
+
+    d_i64_getter!(
+        /// Get the size, in bytes, of this download's contents.
+        size_bytes, "size_bytes");
+
+    d_i64_getter!(
+        /// Get the number of chunks (pieces) in this download.
+        size_chunks, "size_chunks");
+
+    d_i64_getter!(
+        /// Get the number of chunks (pieces) that have completed downloading.
+        ///
+        /// Together with [`Self::size_chunks`], this also lets callers compute overall
+        /// verification progress when combined with [`Self::chunks_hashed`].
+        completed_chunks, "completed_chunks");
+
+    d_i64_getter!(
+        /// Get the number of chunks (pieces) hashed so far during the current (or most recent)
+        /// verification pass.
+        chunks_hashed, "chunks_hashed");
+
+    d_bool_getter!(
+        /// Is this download currently being hash-checked?
+        is_hashing, "hashing");
+
+    d_bool_getter!(
+        /// Did the most recent hash check find corrupted data?
+        hashing_failed, "hashing_failed");
+
+    /// Force rtorrent to re-verify this download's data against its recorded checksums.
+    ///
+    /// Watch the recheck's progress with [`Self::is_hashing`] and
+    /// [`Self::chunks_hashed`]/[`Self::size_chunks`].
+    pub fn check_hash(&self) -> Result<()> {
+        self.get("d.check_hash")?;
+        Ok(())
+    }
+}
@@ -60,13 +60,16 @@ d::MultiBuilder::new(&my_handle, "default")
 use std::sync::Arc;
 use xmlrpc::{Request, Value};
 
+mod asynchronous;
 mod download;
 mod file;
+pub mod ip_filter;
 pub mod multicall;
 mod peer;
 mod tracker;
 pub(crate) mod value_conversion;
 
+pub use asynchronous::AsyncServer;
 pub use download::Download;
 pub use file::File;
 pub use peer::Peer;
@@ -81,12 +84,42 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Debug)]
 pub enum Error {
     XmlRpc(xmlrpc::Error),
+    /// An error from the async HTTP transport used by [`AsyncServer`](crate::AsyncServer).
+    Transport(reqwest::Error),
+    /// rtorrent rejected a call with an XMLRPC fault.
+    ///
+    /// This is raised both for faults returned directly by a call, and for fault structs found
+    /// inside a multicall's per-call results (e.g. [`multicall::system::Batch`]).
+    Fault { code: i64, message: String },
     UnexpectedStructure(String),
 }
 
+impl Error {
+    /// If `value` is an XMLRPC fault struct (`{faultCode, faultString}`), decode it into an
+    /// [`Error::Fault`]. Used to detect faults nested inside multicall responses, which arrive
+    /// as ordinary data rather than as a top-level XMLRPC fault.
+    pub(crate) fn from_fault_value(value: &Value) -> Option<Error> {
+        let Value::Struct(fields) = value else {
+            return None;
+        };
+        let code = fields.get("faultCode").and_then(Value::as_i64)?;
+        let message = fields.get("faultString").and_then(Value::as_str)?;
+        Some(Error::Fault {
+            code,
+            message: message.to_owned(),
+        })
+    }
+}
+
 impl From<xmlrpc::Error> for Error {
     fn from(x: xmlrpc::Error) -> Self {
-        Error::XmlRpc(x)
+        match x {
+            xmlrpc::Error::Fault(fault) => Error::Fault {
+                code: fault.fault_code.into(),
+                message: fault.fault_string,
+            },
+            other => Error::XmlRpc(other),
+        }
     }
 }
 
@@ -96,6 +129,12 @@ impl std::fmt::Display for Error {
             Error::XmlRpc(xe) => {
                 write!(f, "XML-RPC: {}", xe)
             }
+            Error::Transport(te) => {
+                write!(f, "HTTP transport: {}", te)
+            }
+            Error::Fault { code, message } => {
+                write!(f, "rtorrent fault {}: {}", code, message)
+            }
             Error::UnexpectedStructure(us) => {
                 write!(f, "Unexpected XML structure: {}", us)
             }
@@ -107,11 +146,56 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::XmlRpc(xe) => Some(xe),
+            Error::Transport(te) => Some(te),
             _ => None,
         }
     }
 }
 
+/// Decode a `system.methodSignature` response, shared by [`Server::method_signature`] and
+/// [`AsyncServer::method_signature`](crate::AsyncServer::method_signature).
+///
+/// Per the XMLRPC introspection convention, rtorrent reports "no signature registered" as the
+/// bare string `"undef"` rather than an empty array, so that has to be special-cased before
+/// falling through to the usual array-of-arrays decode.
+pub(crate) fn decode_method_signature(value: Value) -> Result<Vec<Vec<String>>> {
+    match value {
+        Value::String(s) if s == "undef" => Ok(vec![]),
+        Value::Array(sigs) => sigs
+            .into_iter()
+            .map(|sig| match sig {
+                Value::Array(types) => types
+                    .into_iter()
+                    .map(|t| match t {
+                        Value::String(s) => Ok(s),
+                        other => Err(Error::UnexpectedStructure(format!(
+                            "expected a string type name, got {:?}",
+                            other
+                        ))),
+                    })
+                    .collect(),
+                other => Err(Error::UnexpectedStructure(format!(
+                    "expected an array signature, got {:?}",
+                    other
+                ))),
+            })
+            .collect(),
+        other => Err(Error::UnexpectedStructure(format!(
+            "expected an array from system.methodSignature, got {:?}",
+            other
+        ))),
+    }
+}
+
+/// Build the positional params `load.normal`/`load.start`/`load.raw`/`load.raw_start` expect: a
+/// leading empty string, the `target` (path/URL/magnet URI or raw bencode) itself, then any
+/// extra `commands` in order.
+fn load_params(target: Value, commands: &[&str]) -> Vec<Value> {
+    let mut params = vec![Value::from(""), target];
+    params.extend(commands.iter().map(|c| Value::from(*c)));
+    params
+}
+
 macro_rules! exec_str_getter {
     ($(#[$meta:meta])* $method: ident) => {
         prim_getter!($(#[$meta])* "exec", $method, String);
@@ -126,4 +210,168 @@ struct ServerInner {
 #[derive(Clone, Debug)]
 pub struct Server {
     inner: Arc<ServerInner>,
-}
\ No newline at end of file
+}
+
+impl Server {
+    /// Create a new `Server` bound to the rtorrent XMLRPC endpoint at `endpoint`.
+    ///
+    /// This does not perform any I/O; the connection is established lazily on first use.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            inner: Arc::new(ServerInner {
+                endpoint: endpoint.into(),
+            }),
+        }
+    }
+
+    pub(crate) fn endpoint(&self) -> &str {
+        &self.inner.endpoint
+    }
+
+    /// Execute a single XMLRPC call against this server's endpoint, blocking the calling thread
+    /// until the response arrives.
+    pub(crate) fn invoke(&self, method: &str, params: Vec<Value>) -> Result<Value> {
+        let mut request = Request::new(method);
+        for param in &params {
+            request = request.arg(param.clone());
+        }
+        Ok(request.call_url(&self.inner.endpoint)?)
+    }
+
+    /// Load a `.torrent` by local file path or URL, optionally starting it immediately, and
+    /// running any extra `commands` (e.g. `"d.directory.set=..."`) against it once loaded.
+    ///
+    /// Wraps `load.normal`/`load.start`.
+    pub fn load_torrent(&self, path_or_url: &str, start: bool, commands: &[&str]) -> Result<()> {
+        let method = if start { "load.start" } else { "load.normal" };
+        self.load(method, Value::from(path_or_url), commands)
+    }
+
+    /// Load a `.torrent` from its raw bencoded contents, optionally starting it immediately, and
+    /// running any extra `commands` against it once loaded.
+    ///
+    /// Wraps `load.raw`/`load.raw_start`.
+    pub fn load_torrent_raw(&self, contents: &[u8], start: bool, commands: &[&str]) -> Result<()> {
+        let method = if start { "load.raw_start" } else { "load.raw" };
+        self.load(method, Value::Binary(contents.to_vec()), commands)
+    }
+
+    /// Load a magnet URI, optionally starting it immediately, and running any extra `commands`
+    /// against it once loaded.
+    ///
+    /// Magnet links are loaded the same way as any other `load.normal`/`load.start` target;
+    /// rtorrent recognizes the `magnet:` scheme itself.
+    pub fn load_magnet(&self, uri: &str, start: bool, commands: &[&str]) -> Result<()> {
+        self.load_torrent(uri, start, commands)
+    }
+
+    fn load(&self, method: &str, target: Value, commands: &[&str]) -> Result<()> {
+        self.invoke(method, load_params(target, commands))?;
+        Ok(())
+    }
+
+    /// List every XMLRPC method this rtorrent instance supports, per `system.listMethods`.
+    pub fn list_methods(&self) -> Result<Vec<String>> {
+        match self.invoke("system.listMethods", vec![])? {
+            Value::Array(methods) => methods
+                .into_iter()
+                .map(|m| match m {
+                    Value::String(s) => Ok(s),
+                    other => Err(Error::UnexpectedStructure(format!(
+                        "expected a string method name, got {:?}",
+                        other
+                    ))),
+                })
+                .collect(),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected an array from system.listMethods, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Get the parameter/return type signature(s) of `name`, per `system.methodSignature`.
+    ///
+    /// Each inner `Vec<String>` is one possible signature, with the return type first, followed
+    /// by each parameter's type (rtorrent methods can be overloaded, hence the outer `Vec`).
+    ///
+    /// Returns an empty `Vec` if rtorrent has no signature registered for `name` -- per the
+    /// XMLRPC introspection convention, this is common and is reported as the bare string
+    /// `"undef"` rather than an empty array.
+    pub fn method_signature(&self, name: &str) -> Result<Vec<Vec<String>>> {
+        decode_method_signature(self.invoke("system.methodSignature", vec![Value::from(name)])?)
+    }
+
+    /// Get the help text rtorrent has registered for `name`, per `system.methodHelp`.
+    pub fn method_help(&self, name: &str) -> Result<String> {
+        match self.invoke("system.methodHelp", vec![Value::from(name)])? {
+            Value::String(s) => Ok(s),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected a string from system.methodHelp, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Check whether this rtorrent instance exposes a method named `name`.
+    ///
+    /// This is a convenience built on [`Self::list_methods`], letting callers feature-detect
+    /// before issuing calls a given rtorrent build may not support.
+    pub fn supports(&self, name: &str) -> Result<bool> {
+        Ok(self.list_methods()?.iter().any(|m| m == name))
+    }
+
+    /// Get a handle to this server's IP-filter table.
+    pub fn ip_filter(&self) -> crate::ip_filter::IpFilter {
+        crate::ip_filter::IpFilter::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_params_includes_empty_prefix_target_and_commands_in_order() {
+        let params = load_params(
+            Value::from("/path/to/some.torrent"),
+            &["d.directory.set=/tmp", "d.start="],
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::from(""),
+                Value::from("/path/to/some.torrent"),
+                Value::from("d.directory.set=/tmp"),
+                Value::from("d.start="),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_params_places_binary_target_right_after_the_empty_prefix() {
+        let params = load_params(Value::Binary(vec![1, 2, 3]), &[]);
+        assert_eq!(params, vec![Value::from(""), Value::Binary(vec![1, 2, 3])]);
+    }
+
+    #[test]
+    fn decode_method_signature_treats_undef_as_no_signature() {
+        assert_eq!(decode_method_signature(Value::from("undef")).unwrap(), Vec::<Vec<String>>::new());
+    }
+
+    #[test]
+    fn decode_method_signature_decodes_array_of_signatures() {
+        let response = Value::Array(vec![Value::Array(vec![Value::from("i"), Value::from("s")])]);
+        assert_eq!(decode_method_signature(response).unwrap(), vec![vec!["i".to_owned(), "s".to_owned()]]);
+    }
+
+    #[test]
+    fn decode_method_signature_rejects_other_strings() {
+        assert!(decode_method_signature(Value::from("not undef")).is_err());
+    }
+
+    #[test]
+    fn decode_method_signature_rejects_unrecognized_shapes() {
+        assert!(decode_method_signature(Value::from(42)).is_err());
+    }
+}
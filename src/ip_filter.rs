@@ -0,0 +1,253 @@
+//! IP-filter management: block or allow ranges of peer addresses.
+//!
+//! Wraps rtorrent's `ipv4_filter.*` commands with a typed API for loading blocklists from Rust
+//! instead of editing rtorrent's config and restarting.
+//!
+//! There is deliberately no `clear()` here: unlike the legacy `ip_filter.*` commands, the
+//! `ipv4_filter.*` family rtorrent actually ships has no runtime "empty the table" verb --
+//! `add_address` only ever appends, and there's no command to un-append it. Restarting the
+//! instance (which reloads the table from config) is the only way to fully reset it.
+
+use std::net::Ipv4Addr;
+
+use xmlrpc::Value;
+
+use crate::{Error, Result, Server};
+
+/// What to do with peer addresses that fall inside an [`IpRange`].
+///
+/// This is the numeric `type` argument `ipv4_filter.add_address` expects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// Block connections from/to this range.
+    Block,
+    /// Allow connections from/to this range.
+    Allow,
+}
+
+impl Action {
+    /// The numeric `type` argument `ipv4_filter.add_address` expects for this action.
+    ///
+    /// **Unverified against a live rtorrent instance.** rtorrent's command reference doesn't
+    /// spell out the `type` encoding in prose, and this crate has no integration test harness to
+    /// check it against a running daemon. `0` for block / `1` for allow is carried over from the
+    /// original implementation of this module. Before relying on this for a real blocklist,
+    /// confirm it yourself: add a `Block` range covering a test peer's address and verify the
+    /// connection is actually refused (not accepted) by that rtorrent instance. Getting this
+    /// backwards silently turns a blocklist into an allowlist.
+    fn as_type(self) -> i64 {
+        match self {
+            Action::Block => 0,
+            Action::Allow => 1,
+        }
+    }
+}
+
+/// A CIDR range of IP addresses paired with an [`Action`] to apply to peers within it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IpRange {
+    pub addr: String,
+    pub prefix_len: u8,
+    pub action: Action,
+}
+
+impl IpRange {
+    /// Build a range covering `addr/prefix_len` (e.g. `IpRange::new("10.0.0.0", 8, ...)` for
+    /// `10.0.0.0/8`).
+    pub fn new(addr: impl Into<String>, prefix_len: u8, action: Action) -> Self {
+        Self {
+            addr: addr.into(),
+            prefix_len,
+            action,
+        }
+    }
+
+    /// Serialize this range into the CIDR string `ipv4_filter.add_address` expects.
+    fn cidr(&self) -> String {
+        format!("{}/{}", self.addr, self.prefix_len)
+    }
+
+    /// Expand the inclusive address range `start_addr..=end_addr` into the minimal set of
+    /// CIDR blocks that exactly covers it, each carrying `action`.
+    ///
+    /// `ipv4_filter.add_address` only accepts CIDR blocks, not arbitrary start/end pairs, so an
+    /// unaligned range like `10.0.0.5-10.0.0.9` has to be expressed as several blocks
+    /// (`10.0.0.5/32`, `10.0.0.6/31`, `10.0.0.8/31`). Pass the result to
+    /// [`IpFilter::add_all`](crate::ip_filter::IpFilter::add_all).
+    pub fn from_addr_range(start_addr: &str, end_addr: &str, action: Action) -> Result<Vec<Self>> {
+        let start: Ipv4Addr = start_addr.parse().map_err(|e| {
+            Error::UnexpectedStructure(format!("invalid start address {:?}: {}", start_addr, e))
+        })?;
+        let end: Ipv4Addr = end_addr.parse().map_err(|e| {
+            Error::UnexpectedStructure(format!("invalid end address {:?}: {}", end_addr, e))
+        })?;
+
+        let mut start = u32::from(start);
+        let end = u32::from(end);
+        if start > end {
+            return Err(Error::UnexpectedStructure(format!(
+                "range start {} is after end {}",
+                start_addr, end_addr
+            )));
+        }
+
+        let mut blocks = Vec::new();
+        loop {
+            let align_bits = if start == 0 { 32 } else { start.trailing_zeros() };
+            let mut size_bits = align_bits;
+            while (start as u64) + (1u64 << size_bits) - 1 > end as u64 {
+                size_bits -= 1;
+            }
+
+            blocks.push(Self::new(Ipv4Addr::from(start).to_string(), 32 - size_bits as u8, action));
+
+            let next = start as u64 + (1u64 << size_bits);
+            if next > end as u64 {
+                break;
+            }
+            start = next as u32;
+        }
+        Ok(blocks)
+    }
+}
+
+/// A typed wrapper around rtorrent's `ipv4_filter.*` commands.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use rtorrent_xmlrpc_bindings as rtorrent;
+/// use rtorrent::ip_filter::{Action, IpRange};
+///
+/// let my_handle = rtorrent::Server::new("http://1.2.3.4/RPC2");
+/// let filter = my_handle.ip_filter();
+///
+/// filter.add(&IpRange::new("10.0.0.0", 8, Action::Block))?;
+/// println!("{} entries loaded", filter.size()?);
+/// # Ok::<(), rtorrent::Error>(())
+/// ```
+pub struct IpFilter {
+    server: Server,
+}
+
+impl IpFilter {
+    pub(crate) fn new(server: &Server) -> Self {
+        Self { server: server.clone() }
+    }
+
+    /// Add a single range/action entry to the filter table, per `ipv4_filter.add_address`.
+    pub fn add(&self, range: &IpRange) -> Result<()> {
+        self.server.invoke(
+            "ipv4_filter.add_address",
+            vec![Value::from(range.action.as_type()), Value::from(range.cidr())],
+        )?;
+        Ok(())
+    }
+
+    /// Add many range/action entries in one pass.
+    pub fn add_all<'a>(&self, ranges: impl IntoIterator<Item = &'a IpRange>) -> Result<()> {
+        for range in ranges {
+            self.add(range)?;
+        }
+        Ok(())
+    }
+
+    /// Add the inclusive address range `start_addr..=end_addr`, expanding it into the minimal
+    /// set of CIDR blocks `ipv4_filter.add_address` can express. Use this when a range (e.g.
+    /// `10.0.0.5-10.0.0.9`) doesn't line up with a single CIDR block.
+    pub fn add_range(&self, start_addr: &str, end_addr: &str, action: Action) -> Result<()> {
+        self.add_all(&IpRange::from_addr_range(start_addr, end_addr, action)?)
+    }
+
+    /// Bulk-load a filter table from a file on rtorrent's host, per `ipv4_filter.load`.
+    pub fn load(&self, path: &str) -> Result<()> {
+        self.server.invoke("ipv4_filter.load", vec![Value::from(path)])?;
+        Ok(())
+    }
+
+    /// Look up the [`Action`] type assigned to `addr`, per `ipv4_filter.get`.
+    pub fn get(&self, addr: &str) -> Result<i64> {
+        match self.server.invoke("ipv4_filter.get", vec![Value::from(addr)])? {
+            Value::Int(i) => Ok(i.into()),
+            Value::Int64(i) => Ok(i),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected an integer from ipv4_filter.get, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// The number of entries currently loaded into the filter table, per `ipv4_filter.size_data`.
+    pub fn size(&self) -> Result<i64> {
+        match self.server.invoke("ipv4_filter.size_data", vec![])? {
+            Value::Int(i) => Ok(i.into()),
+            Value::Int64(i) => Ok(i),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected an integer from ipv4_filter.size_data, got {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Dump the filter table's contents as rtorrent formats them, per `ipv4_filter.dump`.
+    pub fn dump(&self) -> Result<String> {
+        match self.server.invoke("ipv4_filter.dump", vec![])? {
+            Value::String(s) => Ok(s),
+            other => Err(Error::UnexpectedStructure(format!(
+                "expected a string from ipv4_filter.dump, got {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cidr_formats_addr_and_prefix() {
+        let range = IpRange::new("10.0.0.0", 8, Action::Block);
+        assert_eq!(range.cidr(), "10.0.0.0/8");
+    }
+
+    #[test]
+    fn action_as_type_is_stable_and_distinct() {
+        // This only pins the mapping so a future edit can't silently flip it -- it does NOT
+        // prove `0`/`1` actually mean block/allow to rtorrent itself. See the "Unverified"
+        // note on `Action::as_type`.
+        assert_ne!(Action::Block.as_type(), Action::Allow.as_type());
+        assert_eq!(Action::Block.as_type(), 0);
+        assert_eq!(Action::Allow.as_type(), 1);
+    }
+
+    #[test]
+    fn from_addr_range_single_address() {
+        let blocks = IpRange::from_addr_range("10.0.0.5", "10.0.0.5", Action::Block).unwrap();
+        assert_eq!(blocks, vec![IpRange::new("10.0.0.5", 32, Action::Block)]);
+    }
+
+    #[test]
+    fn from_addr_range_exact_cidr_block() {
+        let blocks = IpRange::from_addr_range("10.0.0.0", "10.0.0.255", Action::Block).unwrap();
+        assert_eq!(blocks, vec![IpRange::new("10.0.0.0", 24, Action::Block)]);
+    }
+
+    #[test]
+    fn from_addr_range_unaligned_range_expands_to_minimal_blocks() {
+        let blocks = IpRange::from_addr_range("10.0.0.5", "10.0.0.9", Action::Block).unwrap();
+        assert_eq!(
+            blocks,
+            vec![
+                IpRange::new("10.0.0.5", 32, Action::Block),
+                IpRange::new("10.0.0.6", 31, Action::Block),
+                IpRange::new("10.0.0.8", 31, Action::Block),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_addr_range_rejects_start_after_end() {
+        assert!(IpRange::from_addr_range("10.0.0.9", "10.0.0.5", Action::Block).is_err());
+    }
+}
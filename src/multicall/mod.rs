@@ -0,0 +1,14 @@
+//! Batched XMLRPC queries ("multicalls").
+//!
+//! Rtorrent can answer several related queries in a single round trip. [`d`] wraps
+//! `d.multicall2`, iterating the downloads in a view; see [`d::MultiBuilder`] for an example.
+//! [`p`], [`f`], and [`t`] do the same for a single download's peers, files, and trackers,
+//! keyed by infohash instead of a view. [`system`] wraps `system.multicall`, which batches
+//! arbitrary unrelated calls together instead of iterating a single target.
+
+pub use ops::{d, f, p, t};
+
+pub mod system;
+
+pub(crate) mod ops;
+pub(crate) mod raw;
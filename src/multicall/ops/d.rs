@@ -1,6 +1,6 @@
 //! Rtorrent d.* multicall operations
 
-use crate::{multicall::raw, Server};
+use crate::{multicall::raw, AsyncServer, Server};
 use std::borrow::Cow;
 use std::marker::PhantomData;
 
@@ -38,11 +38,11 @@ super::op_type! {
 ///
 /// The `call()` method can be invoked repeatedly to add more columns to the query -- in the above
 /// example, selecting the `NAME`, `RATIO`, and `SIZE_BYTES` columns.
-pub struct MultiBuilder {
-    pub(crate) inner: raw::MultiBuilder,
+pub struct MultiBuilder<S> {
+    pub(crate) inner: raw::MultiBuilder<S>,
 }
 
-impl MultiBuilder {
+impl MultiBuilder<Server> {
     /// Start building a multicall over downloads in some specific `view` on `server`.
     ///
     /// Views usually include:
@@ -64,6 +64,16 @@ impl MultiBuilder {
     }
 }
 
+impl MultiBuilder<AsyncServer> {
+    /// Start building the same kind of query as [`Self::new`], but bound to an [`AsyncServer`]
+    /// so `.invoke_async()` can be awaited instead of blocking the calling thread.
+    pub fn new_async(server: &AsyncServer, view: &str) -> Self {
+        Self {
+            inner: raw::MultiBuilder::new_async(server, "d.multicall2", "", view),
+        }
+    }
+}
+
 macro_rules! define_builder {
     ( $(#[$meta:meta])* $prev: ident, $name: ident, $($phantoms:ident $ty:ident),* | $phantom_last:ident $ty_last:ident ) => {
         ops::define_builder!($(#[$meta])* DownloadMultiCallOp, $prev, $name, $($phantoms $ty),* | $phantom_last $ty_last);
@@ -132,9 +142,22 @@ d_op_const!(
 d_op_const!(
     /// Get the size, in bytes, of the torrent contents.
     SIZE_BYTES, i64, "size_bytes");
+d_op_const!(
+    /// Get the number of chunks (pieces) in the torrent.
+    SIZE_CHUNKS, i64, "size_chunks");
 d_op_const!(
     /// Get the number of files associated with this download.
     SIZE_FILES, i64, "size_files");
+d_op_const!(
+    /// Is this download currently being hash-checked?
+    HASHING, bool, "hashing");
+d_op_const!(
+    /// Did the most recent hash check find corrupted data?
+    HASHING_FAILED, bool, "hashing_failed");
+d_op_const!(
+    /// Get the number of chunks (pieces) hashed so far during the current (or most recent)
+    /// verification pass.
+    CHUNKS_HASHED, i64, "chunks_hashed");
 d_op_const!(
     /// Get the state (`false` is stopped).
     STATE, bool, "state");
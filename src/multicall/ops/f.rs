@@ -0,0 +1,89 @@
+//! Rtorrent f.* multicall operations
+
+use crate::{multicall::raw, AsyncServer, Server};
+
+super::op_type! {
+    /// An `f.*` operation for multicalls
+    FileMultiCallOp
+}
+
+/// `MultiBuilder` is a tool for building queries across every file of a download.
+///
+/// The constructed query is executed in a single XMLRPC call.  The query results are in
+/// convenient Rust types.
+///
+/// ## Usage
+///
+/// Example: Print path and completion progress for every file of a download.
+///
+/// ```no_run
+/// use rtorrent_xmlrpc_bindings as rtorrent;
+/// use rtorrent::multicall::f;
+///
+/// let my_handle = rtorrent::Server::new("http://1.2.3.4/RPC2");
+///
+/// f::MultiBuilder::new(&my_handle, "D1234...")
+///     .call(f::PATH)
+///     .call(f::SIZE_BYTES)
+///     .call(f::COMPLETED_CHUNKS)
+///     .invoke()?
+///     .iter()
+///     .for_each(|(path, size_bytes, completed_chunks)| {
+///         println!("{}: {} bytes, {} chunks done", path, size_bytes, completed_chunks);
+///     });
+/// # Ok::<(), rtorrent::Error>(())
+/// ```
+pub struct MultiBuilder<S> {
+    pub(crate) inner: raw::MultiBuilder<S>,
+}
+
+impl MultiBuilder<Server> {
+    /// Start building a multicall over the files of the download identified by `hash`.
+    pub fn new(server: &Server, hash: &str) -> Self {
+        Self {
+            inner: raw::MultiBuilder::new(server, "f.multicall", "", hash),
+        }
+    }
+}
+
+impl MultiBuilder<AsyncServer> {
+    /// Start building the same kind of query as [`Self::new`], but bound to an [`AsyncServer`]
+    /// so `.invoke_async()` can be awaited instead of blocking the calling thread.
+    pub fn new_async(server: &AsyncServer, hash: &str) -> Self {
+        Self {
+            inner: raw::MultiBuilder::new_async(server, "f.multicall", "", hash),
+        }
+    }
+}
+
+macro_rules! define_builder {
+    ( $(#[$meta:meta])* $prev: ident, $name: ident, $($phantoms:ident $ty:ident),* | $phantom_last:ident $ty_last:ident ) => {
+        ops::define_builder!($(#[$meta])* FileMultiCallOp, $prev, $name, $($phantoms $ty),* | $phantom_last $ty_last);
+    }
+}
+pub(crate) use define_builder;
+
+macro_rules! f_op_const {
+    ( $(#[$meta:meta])* $name: ident, $res: ty, $api: literal ) => {
+        super::op_const!( $(#[$meta])* FileMultiCallOp, $name, $res, "f.", $api);
+    };
+}
+
+f_op_const!(
+    /// The file's path, relative to the download's base directory.
+    PATH, String, "path");
+f_op_const!(
+    /// Get the size, in bytes, of this file.
+    SIZE_BYTES, i64, "size_bytes");
+f_op_const!(
+    /// Get the number of chunks (pieces) this file spans.
+    SIZE_CHUNKS, i64, "size_chunks");
+f_op_const!(
+    /// Get the number of chunks of this file that have completed.
+    COMPLETED_CHUNKS, i64, "completed_chunks");
+f_op_const!(
+    /// Get the file's download priority (`0` = off, `1` = normal, `2` = high).
+    PRIORITY, i64, "priority");
+f_op_const!(
+    /// Is this file created (does it exist on disk)?
+    IS_CREATED, bool, "is_created");
@@ -0,0 +1,145 @@
+//! Macros that build a typed `*.multicall` query builder on top of [`super::raw`].
+//!
+//! [`op_type!`] declares a marker type identifying which rtorrent entity (download, peer, file,
+//! tracker, ...) a builder's columns belong to, so columns from one can't be pushed onto a
+//! builder for another. [`op_const!`] declares a single typed column. [`define_builder!`] wires
+//! up one arity of the builder's typestate chain: a `.call()` that accepts one more column and
+//! an `.invoke()` that decodes each result row into a tuple of the requested types.
+
+pub mod d;
+pub mod f;
+pub mod p;
+pub mod t;
+
+use std::marker::PhantomData;
+
+/// Declares a zero-sized marker type identifying which rtorrent object a multicall operates on.
+macro_rules! op_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        pub struct $name;
+    };
+}
+pub(crate) use op_type;
+
+/// A single typed column: which entity it applies to (`Kind`), the Rust type its result decodes
+/// into (`T`), and the rtorrent API method to invoke for it.
+pub struct Op<Kind, T> {
+    pub(crate) method: &'static str,
+    _kind: PhantomData<fn() -> Kind>,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<Kind, T> Op<Kind, T> {
+    pub(crate) const fn new(method: &'static str) -> Self {
+        Self {
+            method,
+            _kind: PhantomData,
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<Kind, T> Clone for Op<Kind, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Kind, T> Copy for Op<Kind, T> {}
+
+/// Declares `pub const NAME: Op<Kind, T>`, prefixing `$api` with `$prefix` (e.g. `"d."`) to form
+/// the full rtorrent API method name.
+macro_rules! op_const {
+    ($(#[$meta:meta])* $kind:ty, $name:ident, $res:ty, $prefix:literal, $api:literal) => {
+        $(#[$meta])*
+        pub const $name: $crate::multicall::ops::Op<$kind, $res> =
+            $crate::multicall::ops::Op::new(concat!($prefix, $api));
+    };
+}
+pub(crate) use op_const;
+
+/// Generates one arity of a builder's typestate chain on top of [`super::raw::MultiBuilder`].
+///
+/// `$prev` is the struct gaining a `.call()` that returns `$name`, which accumulates the
+/// previously-collected phantom types (`$phantoms $ty`) plus the newly added one
+/// (`$phantom_last $ty_last`), and whose `.invoke()`/`.invoke_async()` decodes each result row
+/// into a tuple of all of them in order.
+///
+/// Every generated struct carries the transport generic `S` from [`super::raw::MultiBuilder<S>`]
+/// straight through, so `.invoke()` only exists for `$name<Server, ..>` and `.invoke_async()`
+/// only for `$name<AsyncServer, ..>` -- the same typestate discipline that already keeps columns
+/// from one entity off a builder for another.
+macro_rules! define_builder {
+    ($(#[$meta:meta])* $kind:ty, $prev:ident, $name:ident, $($phantoms:ident $ty:ident),* | $phantom_last:ident $ty_last:ident) => {
+        #[allow(non_snake_case)]
+        $(#[$meta])*
+        pub struct $name<S, $($ty,)* $ty_last> {
+            pub(crate) inner: $crate::multicall::raw::MultiBuilder<S>,
+            $(pub(crate) $phantoms: ::std::marker::PhantomData<fn() -> $ty>,)*
+            pub(crate) $phantom_last: ::std::marker::PhantomData<fn() -> $ty_last>,
+        }
+
+        impl<S, $($ty,)*> $prev<S, $($ty,)*> {
+            /// Add another column to this query.
+            pub fn call<$ty_last: $crate::TryFromValue>(
+                mut self,
+                op: $crate::multicall::ops::Op<$kind, $ty_last>,
+            ) -> $name<S, $($ty,)* $ty_last> {
+                self.inner.push_column(op.method);
+                $name {
+                    inner: self.inner,
+                    $($phantoms: ::std::marker::PhantomData,)*
+                    $phantom_last: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<$($ty: $crate::TryFromValue,)* $ty_last: $crate::TryFromValue> $name<$crate::Server, $($ty,)* $ty_last> {
+            /// Execute the multicall, decoding each result row into a typed tuple.
+            pub fn invoke(&self) -> $crate::Result<Vec<($($ty,)* $ty_last,)>> {
+                self.inner
+                    .invoke()?
+                    .into_iter()
+                    .map(|row| {
+                        let mut cols = row.into_iter();
+                        $(
+                            let $phantoms = $ty::try_from_value(&cols.next().ok_or_else(|| {
+                                $crate::Error::UnexpectedStructure("multicall row is missing a column".into())
+                            })?)?;
+                        )*
+                        let $phantom_last = $ty_last::try_from_value(&cols.next().ok_or_else(|| {
+                            $crate::Error::UnexpectedStructure("multicall row is missing a column".into())
+                        })?)?;
+                        Ok(($($phantoms,)* $phantom_last,))
+                    })
+                    .collect()
+            }
+        }
+
+        impl<$($ty: $crate::TryFromValue,)* $ty_last: $crate::TryFromValue> $name<$crate::AsyncServer, $($ty,)* $ty_last> {
+            /// Execute the multicall over rtorrent's async transport, decoding each result row
+            /// into a typed tuple.
+            pub async fn invoke_async(&self) -> $crate::Result<Vec<($($ty,)* $ty_last,)>> {
+                self.inner
+                    .invoke_async()
+                    .await?
+                    .into_iter()
+                    .map(|row| {
+                        let mut cols = row.into_iter();
+                        $(
+                            let $phantoms = $ty::try_from_value(&cols.next().ok_or_else(|| {
+                                $crate::Error::UnexpectedStructure("multicall row is missing a column".into())
+                            })?)?;
+                        )*
+                        let $phantom_last = $ty_last::try_from_value(&cols.next().ok_or_else(|| {
+                            $crate::Error::UnexpectedStructure("multicall row is missing a column".into())
+                        })?)?;
+                        Ok(($($phantoms,)* $phantom_last,))
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+pub(crate) use define_builder;
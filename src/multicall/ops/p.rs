@@ -0,0 +1,94 @@
+//! Rtorrent p.* multicall operations
+
+use crate::{multicall::raw, AsyncServer, Server};
+
+super::op_type! {
+    /// A `p.*` operation for multicalls
+    PeerMultiCallOp
+}
+
+/// `MultiBuilder` is a tool for building queries across every peer of a download.
+///
+/// The constructed query is executed in a single XMLRPC call.  The query results are in
+/// convenient Rust types.
+///
+/// ## Usage
+///
+/// Example: Print address and download rate for every peer of a download.
+///
+/// ```no_run
+/// use rtorrent_xmlrpc_bindings as rtorrent;
+/// use rtorrent::multicall::p;
+///
+/// let my_handle = rtorrent::Server::new("http://1.2.3.4/RPC2");
+///
+/// p::MultiBuilder::new(&my_handle, "D1234...")
+///     .call(p::ADDRESS)
+///     .call(p::DOWN_RATE)
+///     .invoke()?
+///     .iter()
+///     .for_each(|(address, down_rate)| {
+///         println!("{}: {} B/s", address, down_rate);
+///     });
+/// # Ok::<(), rtorrent::Error>(())
+/// ```
+pub struct MultiBuilder<S> {
+    pub(crate) inner: raw::MultiBuilder<S>,
+}
+
+impl MultiBuilder<Server> {
+    /// Start building a multicall over the peers of the download identified by `hash`.
+    pub fn new(server: &Server, hash: &str) -> Self {
+        Self {
+            inner: raw::MultiBuilder::new(server, "p.multicall", "", hash),
+        }
+    }
+}
+
+impl MultiBuilder<AsyncServer> {
+    /// Start building the same kind of query as [`Self::new`], but bound to an [`AsyncServer`]
+    /// so `.invoke_async()` can be awaited instead of blocking the calling thread.
+    pub fn new_async(server: &AsyncServer, hash: &str) -> Self {
+        Self {
+            inner: raw::MultiBuilder::new_async(server, "p.multicall", "", hash),
+        }
+    }
+}
+
+macro_rules! define_builder {
+    ( $(#[$meta:meta])* $prev: ident, $name: ident, $($phantoms:ident $ty:ident),* | $phantom_last:ident $ty_last:ident ) => {
+        ops::define_builder!($(#[$meta])* PeerMultiCallOp, $prev, $name, $($phantoms $ty),* | $phantom_last $ty_last);
+    }
+}
+pub(crate) use define_builder;
+
+macro_rules! p_op_const {
+    ( $(#[$meta:meta])* $name: ident, $res: ty, $api: literal ) => {
+        super::op_const!( $(#[$meta])* PeerMultiCallOp, $name, $res, "p.", $api);
+    };
+}
+
+p_op_const!(
+    /// The peer's address, in `ip:port` form.
+    ADDRESS, String, "address");
+p_op_const!(
+    /// The remote client's advertised version string.
+    CLIENT_VERSION, String, "client_version");
+p_op_const!(
+    /// Get the download rate from this peer, in bytes per second.
+    DOWN_RATE, i64, "down_rate");
+p_op_const!(
+    /// Get the total bytes downloaded from this peer.
+    DOWN_TOTAL, i64, "down_total");
+p_op_const!(
+    /// Get the upload rate to this peer, in bytes per second.
+    UP_RATE, i64, "up_rate");
+p_op_const!(
+    /// Get the total bytes uploaded to this peer.
+    UP_TOTAL, i64, "up_total");
+p_op_const!(
+    /// Is this peer encrypted?
+    IS_ENCRYPTED, bool, "is_encrypted");
+p_op_const!(
+    /// Is this an incoming connection?
+    IS_INCOMING, bool, "is_incoming");
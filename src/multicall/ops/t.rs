@@ -0,0 +1,85 @@
+//! Rtorrent t.* multicall operations
+
+use crate::{multicall::raw, AsyncServer, Server};
+
+super::op_type! {
+    /// A `t.*` operation for multicalls
+    TrackerMultiCallOp
+}
+
+/// `MultiBuilder` is a tool for building queries across every tracker of a download.
+///
+/// The constructed query is executed in a single XMLRPC call.  The query results are in
+/// convenient Rust types.
+///
+/// ## Usage
+///
+/// Example: Print URL and enabled state for every tracker of a download.
+///
+/// ```no_run
+/// use rtorrent_xmlrpc_bindings as rtorrent;
+/// use rtorrent::multicall::t;
+///
+/// let my_handle = rtorrent::Server::new("http://1.2.3.4/RPC2");
+///
+/// t::MultiBuilder::new(&my_handle, "D1234...")
+///     .call(t::URL)
+///     .call(t::IS_ENABLED)
+///     .invoke()?
+///     .iter()
+///     .for_each(|(url, is_enabled)| {
+///         println!("{}: enabled={}", url, is_enabled);
+///     });
+/// # Ok::<(), rtorrent::Error>(())
+/// ```
+pub struct MultiBuilder<S> {
+    pub(crate) inner: raw::MultiBuilder<S>,
+}
+
+impl MultiBuilder<Server> {
+    /// Start building a multicall over the trackers of the download identified by `hash`.
+    pub fn new(server: &Server, hash: &str) -> Self {
+        Self {
+            inner: raw::MultiBuilder::new(server, "t.multicall", "", hash),
+        }
+    }
+}
+
+impl MultiBuilder<AsyncServer> {
+    /// Start building the same kind of query as [`Self::new`], but bound to an [`AsyncServer`]
+    /// so `.invoke_async()` can be awaited instead of blocking the calling thread.
+    pub fn new_async(server: &AsyncServer, hash: &str) -> Self {
+        Self {
+            inner: raw::MultiBuilder::new_async(server, "t.multicall", "", hash),
+        }
+    }
+}
+
+macro_rules! define_builder {
+    ( $(#[$meta:meta])* $prev: ident, $name: ident, $($phantoms:ident $ty:ident),* | $phantom_last:ident $ty_last:ident ) => {
+        ops::define_builder!($(#[$meta])* TrackerMultiCallOp, $prev, $name, $($phantoms $ty),* | $phantom_last $ty_last);
+    }
+}
+pub(crate) use define_builder;
+
+macro_rules! t_op_const {
+    ( $(#[$meta:meta])* $name: ident, $res: ty, $api: literal ) => {
+        super::op_const!( $(#[$meta])* TrackerMultiCallOp, $name, $res, "t.", $api);
+    };
+}
+
+t_op_const!(
+    /// The tracker's announce URL.
+    URL, String, "url");
+t_op_const!(
+    /// Is this tracker currently enabled?
+    IS_ENABLED, bool, "is_enabled");
+t_op_const!(
+    /// Get the number of seeders this tracker last reported.
+    SCRAPE_COMPLETE, i64, "scrape_complete");
+t_op_const!(
+    /// Get the number of leechers this tracker last reported.
+    SCRAPE_INCOMPLETE, i64, "scrape_incomplete");
+t_op_const!(
+    /// Get the number of times this tracker has been successfully scraped.
+    SCRAPE_COUNTER, i64, "scrape_counter");
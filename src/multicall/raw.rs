@@ -0,0 +1,92 @@
+//! Untyped machinery shared by every `*.multicall` builder.
+//!
+//! [`crate::multicall::ops`] layers compile-time arity checking and typed decoding on top of
+//! this; this module only knows how to assemble the XMLRPC request and split the response back
+//! into per-row result arrays.
+
+use xmlrpc::Value;
+
+use crate::{AsyncServer, Error, Result, Server};
+
+/// The untyped multicall request builder, generic over which server type (`S`) it is bound to.
+///
+/// `S` is always [`Server`] or [`AsyncServer`] in practice: [`Self::new`]/[`Self::invoke`] are
+/// only defined for the former and [`Self::new_async`]/[`Self::invoke_async`] only for the
+/// latter, so a builder built for one transport simply has no method to invoke the other --
+/// calling the wrong one is a compile error, not a runtime one. Typed builders (e.g.
+/// [`crate::multicall::d`]) hold one of these and add compile-time column tracking on top,
+/// threading `S` straight through.
+pub struct MultiBuilder<S> {
+    server: S,
+    method: &'static str,
+    default_params: String,
+    target: String,
+    columns: Vec<&'static str>,
+}
+
+impl<S> MultiBuilder<S> {
+    pub(crate) fn push_column(&mut self, api_method: &'static str) {
+        self.columns.push(api_method);
+    }
+
+    fn params(&self) -> Vec<Value> {
+        let mut params = vec![Value::from(self.target.clone()), Value::from(self.default_params.clone())];
+        params.extend(self.columns.iter().map(|c| Value::from(*c)));
+        params
+    }
+}
+
+impl MultiBuilder<Server> {
+    pub(crate) fn new(server: &Server, method: &'static str, default_params: &str, target: &str) -> Self {
+        Self {
+            server: server.clone(),
+            method,
+            default_params: default_params.to_owned(),
+            target: target.to_owned(),
+            columns: Vec::new(),
+        }
+    }
+
+    /// Execute the multicall and return one row of raw [`Value`]s per matched item.
+    pub(crate) fn invoke(&self) -> Result<Vec<Vec<Value>>> {
+        decode_rows(self.server.invoke(self.method, self.params())?)
+    }
+}
+
+impl MultiBuilder<AsyncServer> {
+    /// Build a multicall bound to rtorrent's async transport instead of the blocking one.
+    pub(crate) fn new_async(server: &AsyncServer, method: &'static str, default_params: &str, target: &str) -> Self {
+        Self {
+            server: server.clone(),
+            method,
+            default_params: default_params.to_owned(),
+            target: target.to_owned(),
+            columns: Vec::new(),
+        }
+    }
+
+    /// Execute the multicall over rtorrent's async transport, returning one row of raw
+    /// [`Value`]s per matched item.
+    pub(crate) async fn invoke_async(&self) -> Result<Vec<Vec<Value>>> {
+        decode_rows(self.server.invoke(self.method, self.params()).await?)
+    }
+}
+
+fn decode_rows(response: Value) -> Result<Vec<Vec<Value>>> {
+    match response {
+        Value::Array(rows) => rows
+            .into_iter()
+            .map(|row| match row {
+                Value::Array(cols) => Ok(cols),
+                other => Err(Error::UnexpectedStructure(format!(
+                    "expected a multicall row array, got {:?}",
+                    other
+                ))),
+            })
+            .collect(),
+        other => Err(Error::UnexpectedStructure(format!(
+            "expected a multicall result array, got {:?}",
+            other
+        ))),
+    }
+}
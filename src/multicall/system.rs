@@ -0,0 +1,187 @@
+//! `system.multicall`: batch arbitrary, unrelated calls into a single round trip.
+//!
+//! Unlike [`crate::multicall::d`] and its siblings, which each iterate one kind of rtorrent
+//! object across a view or target, `system.multicall` just runs a list of independent calls and
+//! returns one result per call.  Use it to combine calls that have nothing to do with each other
+//! (e.g. a `d.name`, a `throttle.global_up.rate`, and a `load.start`) into one HTTP request.
+
+use xmlrpc::Value;
+
+use crate::{Error, Result, Server};
+
+/// One call queued in a [`Batch`]: the rtorrent method name and its positional arguments.
+struct Entry {
+    method: String,
+    params: Vec<Value>,
+}
+
+/// Builds a `system.multicall` request out of arbitrary, unrelated calls.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use rtorrent_xmlrpc_bindings as rtorrent;
+/// use rtorrent::multicall::system::Batch;
+///
+/// let my_handle = rtorrent::Server::new("http://1.2.3.4/RPC2");
+///
+/// let results = Batch::new()
+///     .push("d.name", vec!["D1234...".into()])
+///     .push("throttle.global_up.rate", vec![])
+///     .push("load.start", vec!["".into(), "/path/to/some.torrent".into()])
+///     .invoke(&my_handle)?;
+///
+/// for result in results {
+///     match result {
+///         Ok(value) => println!("{:?}", value),
+///         Err(e) => eprintln!("call failed: {}", e),
+///     }
+/// }
+/// # Ok::<(), rtorrent::Error>(())
+/// ```
+#[derive(Default)]
+pub struct Batch {
+    entries: Vec<Entry>,
+}
+
+impl Batch {
+    /// Start an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a call onto the batch. `method` is the full rtorrent API method name (e.g.
+    /// `"d.name"`), and `params` are its positional arguments.
+    pub fn push(mut self, method: impl Into<String>, params: Vec<Value>) -> Self {
+        self.entries.push(Entry {
+            method: method.into(),
+            params,
+        });
+        self
+    }
+
+    fn request_params(&self) -> Vec<Value> {
+        let calls = self
+            .entries
+            .iter()
+            .map(|entry| {
+                Value::Struct(
+                    [
+                        ("methodName".to_owned(), Value::String(entry.method.clone())),
+                        ("params".to_owned(), Value::Array(entry.params.clone())),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+            })
+            .collect();
+        vec![Value::Array(calls)]
+    }
+
+    /// Execute the batch in a single `system.multicall` round trip.
+    ///
+    /// The returned `Vec` has one entry per queued call, in order, so it can be zipped back up
+    /// against the calls that were pushed. A call that rtorrent reports a fault for is surfaced
+    /// as `Err(Error::Fault { .. })` in its slot rather than failing the whole batch.
+    pub fn invoke(self, server: &Server) -> Result<Vec<Result<Value>>> {
+        decode_batch(server.invoke("system.multicall", self.request_params())?)
+    }
+
+    /// Execute the batch over rtorrent's async transport instead of blocking the calling thread.
+    pub async fn invoke_async(self, server: &crate::AsyncServer) -> Result<Vec<Result<Value>>> {
+        decode_batch(server.invoke("system.multicall", self.request_params()).await?)
+    }
+}
+
+fn decode_batch(response: Value) -> Result<Vec<Result<Value>>> {
+    match response {
+        Value::Array(results) => results.into_iter().map(decode_call_result).collect(),
+        other => Err(Error::UnexpectedStructure(format!(
+            "expected a system.multicall result array, got {:?}",
+            other
+        ))),
+    }
+}
+
+/// Per the protocol, each element of a `system.multicall` response array is either a one-item
+/// array `[value]` on success, or a `{faultCode, faultString}` struct on failure.
+fn decode_call_result(entry: Value) -> Result<Result<Value>> {
+    match entry {
+        Value::Array(mut values) if values.len() == 1 => Ok(Ok(values.remove(0))),
+        Value::Struct(_) => match Error::from_fault_value(&entry) {
+            Some(fault) => Ok(Err(fault)),
+            None => Err(Error::UnexpectedStructure(format!(
+                "expected a fault struct in system.multicall result, got {:?}",
+                entry
+            ))),
+        },
+        other => Err(Error::UnexpectedStructure(format!(
+            "expected a one-item array or fault struct in system.multicall result, got {:?}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fault_value(code: i64, message: &str) -> Value {
+        Value::Struct(
+            [
+                ("faultCode".to_owned(), Value::from(code)),
+                ("faultString".to_owned(), Value::from(message)),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+
+    #[test]
+    fn request_params_encodes_method_name_and_params_per_entry() {
+        let params = Batch::new()
+            .push("d.name", vec![Value::from("D1234")])
+            .push("throttle.global_up.rate", vec![])
+            .request_params();
+
+        let Value::Array(calls) = params.into_iter().next().unwrap() else {
+            panic!("expected request_params to wrap the calls in a single array");
+        };
+        assert_eq!(calls.len(), 2);
+
+        let Value::Struct(first) = &calls[0] else {
+            panic!("expected each call to be a struct");
+        };
+        assert_eq!(first.get("methodName"), Some(&Value::from("d.name")));
+        assert_eq!(first.get("params"), Some(&Value::Array(vec![Value::from("D1234")])));
+
+        let Value::Struct(second) = &calls[1] else {
+            panic!("expected each call to be a struct");
+        };
+        assert_eq!(second.get("methodName"), Some(&Value::from("throttle.global_up.rate")));
+        assert_eq!(second.get("params"), Some(&Value::Array(vec![])));
+    }
+
+    #[test]
+    fn decode_call_result_unwraps_one_item_success_array() {
+        let result = decode_call_result(Value::Array(vec![Value::from("ok")])).unwrap();
+        assert_eq!(result.unwrap(), Value::from("ok"));
+    }
+
+    #[test]
+    fn decode_call_result_maps_fault_struct_to_error_fault() {
+        let result = decode_call_result(fault_value(-1, "Unknown method")).unwrap();
+        match result {
+            Err(Error::Fault { code, message }) => {
+                assert_eq!(code, -1);
+                assert_eq!(message, "Unknown method");
+            }
+            other => panic!("expected Err(Error::Fault {{ .. }}), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn decode_call_result_rejects_unrecognized_shapes() {
+        assert!(decode_call_result(Value::from("neither an array nor a struct")).is_err());
+    }
+}